@@ -0,0 +1,263 @@
+// Active learning query engine for prioritizing samples worth labeling next.
+//
+// Consumes `PluginConfig.confidence_threshold`, which was previously unused, to turn
+// raw model predictions into a ranked labeling queue. `next_batch` does not read
+// `PluginConfig.enable_active_learning`.
+
+use uuid::Uuid;
+
+use crate::{LabelingProject, PluginConfig, SystemMetrics};
+
+/// A model's predicted class probability distribution for one unlabeled sample.
+#[derive(Debug, Clone)]
+pub struct SamplePrediction {
+    pub sample_id: Uuid,
+    /// Predicted probability for each class; does not need to sum to exactly 1.0.
+    pub class_probabilities: Vec<f64>,
+}
+
+impl SamplePrediction {
+    fn max_probability(&self) -> f64 {
+        self.class_probabilities
+            .iter()
+            .cloned()
+            .fold(f64::MIN, f64::max)
+    }
+}
+
+/// Pluggable sample-prioritization strategies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActiveLearningStrategy {
+    /// score = 1 − max_p; higher when the model is least confident.
+    LeastConfidence,
+    /// score = −(p_top1 − p_top2); a smaller margin between the top two classes scores higher.
+    MarginSampling,
+    /// score = −Σ p_i·ln(p_i); higher entropy scores higher.
+    Entropy,
+}
+
+impl ActiveLearningStrategy {
+    fn score(&self, probs: &[f64]) -> f64 {
+        match self {
+            ActiveLearningStrategy::LeastConfidence => {
+                let max_p = probs.iter().cloned().fold(f64::MIN, f64::max);
+                1.0 - max_p
+            }
+            ActiveLearningStrategy::MarginSampling => {
+                let mut sorted = probs.to_vec();
+                sorted.sort_by(|a, b| b.total_cmp(a));
+                let top1 = sorted.first().copied().unwrap_or(0.0);
+                let top2 = sorted.get(1).copied().unwrap_or(0.0);
+                -(top1 - top2)
+            }
+            ActiveLearningStrategy::Entropy => -probs
+                .iter()
+                .filter(|p| **p > 0.0)
+                .map(|p| p * p.ln())
+                .sum::<f64>(),
+        }
+    }
+}
+
+impl LabelingProject {
+    /// Rank unlabeled `predictions` by how worthwhile they are to hand to a human
+    /// annotator next, under the given `strategy`.
+    ///
+    /// Samples whose top predicted probability already exceeds
+    /// `config.confidence_threshold` are treated as auto-labeled: they are excluded
+    /// from the queue and `metrics.samples_labeled` is incremented for each one.
+    /// The remaining samples are sorted descending by the strategy's score and the
+    /// first `n` sample ids are returned.
+    pub fn next_batch(
+        &self,
+        n: usize,
+        predictions: &[SamplePrediction],
+        strategy: ActiveLearningStrategy,
+        config: &PluginConfig,
+        metrics: &mut SystemMetrics,
+    ) -> Vec<Uuid> {
+        let mut ranked: Vec<(Uuid, f64)> = Vec::with_capacity(predictions.len());
+
+        for prediction in predictions {
+            if prediction.max_probability() > config.confidence_threshold {
+                metrics.samples_labeled += 1;
+                continue;
+            }
+
+            // A degenerate model output (e.g. a NaN from a broken softmax) can't be
+            // meaningfully prioritized, so such samples are dropped from the queue
+            // rather than crashing the sort.
+            let score = strategy.score(&prediction.class_probabilities);
+            if !score.is_finite() {
+                continue;
+            }
+
+            ranked.push((prediction.sample_id, score));
+        }
+
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranked.into_iter().take(n).map(|(id, _)| id).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ProjectStatus, SystemMetrics};
+    use chrono::Utc;
+
+    fn test_project() -> LabelingProject {
+        LabelingProject {
+            id: Uuid::new_v4(),
+            name: "test".to_string(),
+            task_type: "text_classification".to_string(),
+            status: ProjectStatus::InProgress,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            progress: 0.0,
+            schema: None,
+        }
+    }
+
+    fn test_config() -> PluginConfig {
+        PluginConfig {
+            confidence_threshold: 0.9,
+            enable_active_learning: true,
+            max_concurrent_projects: 10,
+            quality_assurance_enabled: true,
+        }
+    }
+
+    fn test_metrics() -> SystemMetrics {
+        SystemMetrics {
+            total_projects: 0,
+            active_project_count: 0,
+            samples_labeled: 0,
+            accuracy_score: 0.0,
+        }
+    }
+
+    #[test]
+    fn auto_labels_confident_samples_and_skips_them() {
+        let project = test_project();
+        let config = test_config();
+        let mut metrics = test_metrics();
+
+        let confident = Uuid::new_v4();
+        let ambiguous = Uuid::new_v4();
+        let predictions = vec![
+            SamplePrediction {
+                sample_id: confident,
+                class_probabilities: vec![0.97, 0.02, 0.01],
+            },
+            SamplePrediction {
+                sample_id: ambiguous,
+                class_probabilities: vec![0.4, 0.35, 0.25],
+            },
+        ];
+
+        let batch = project.next_batch(
+            10,
+            &predictions,
+            ActiveLearningStrategy::LeastConfidence,
+            &config,
+            &mut metrics,
+        );
+
+        assert_eq!(batch, vec![ambiguous]);
+        assert_eq!(metrics.samples_labeled, 1);
+    }
+
+    #[test]
+    fn margin_sampling_prioritizes_the_closest_top_two() {
+        let project = test_project();
+        let config = test_config();
+        let mut metrics = test_metrics();
+
+        let close_call = Uuid::new_v4();
+        let clear_cut = Uuid::new_v4();
+        let predictions = vec![
+            SamplePrediction {
+                sample_id: clear_cut,
+                class_probabilities: vec![0.6, 0.3, 0.1],
+            },
+            SamplePrediction {
+                sample_id: close_call,
+                class_probabilities: vec![0.5, 0.48, 0.02],
+            },
+        ];
+
+        let batch = project.next_batch(
+            1,
+            &predictions,
+            ActiveLearningStrategy::MarginSampling,
+            &config,
+            &mut metrics,
+        );
+
+        assert_eq!(batch, vec![close_call]);
+    }
+
+    #[test]
+    fn entropy_ranks_uniform_distribution_highest() {
+        let project = test_project();
+        let config = test_config();
+        let mut metrics = test_metrics();
+
+        let uniform = Uuid::new_v4();
+        let skewed = Uuid::new_v4();
+        let predictions = vec![
+            SamplePrediction {
+                sample_id: skewed,
+                class_probabilities: vec![0.7, 0.2, 0.1],
+            },
+            SamplePrediction {
+                sample_id: uniform,
+                class_probabilities: vec![0.34, 0.33, 0.33],
+            },
+        ];
+
+        let batch = project.next_batch(
+            2,
+            &predictions,
+            ActiveLearningStrategy::Entropy,
+            &config,
+            &mut metrics,
+        );
+
+        assert_eq!(batch, vec![uniform, skewed]);
+    }
+
+    #[test]
+    fn drops_samples_with_nan_predictions_instead_of_panicking() {
+        let project = test_project();
+        let config = test_config();
+        let mut metrics = test_metrics();
+
+        // A degenerate softmax output containing NaN produces a NaN margin score
+        // (NaN propagates through the top1 - top2 subtraction); this must not
+        // panic the sort and must not be surfaced as a priority sample.
+        let broken = Uuid::new_v4();
+        let ambiguous = Uuid::new_v4();
+        let predictions = vec![
+            SamplePrediction {
+                sample_id: broken,
+                class_probabilities: vec![f64::NAN, 0.3, 0.2],
+            },
+            SamplePrediction {
+                sample_id: ambiguous,
+                class_probabilities: vec![0.4, 0.35, 0.25],
+            },
+        ];
+
+        let batch = project.next_batch(
+            10,
+            &predictions,
+            ActiveLearningStrategy::MarginSampling,
+            &config,
+            &mut metrics,
+        );
+
+        assert_eq!(batch, vec![ambiguous]);
+    }
+}
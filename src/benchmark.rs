@@ -0,0 +1,205 @@
+// Workload benchmark runner.
+//
+// Drives the labeling pipeline through a JSON-defined workload and reports
+// throughput and latency numbers, so operators can catch performance
+// regressions across versions. Workload files carry no ground-truth labels or
+// multi-annotator data, so there's nothing to compute a real accuracy/agreement
+// figure from here; that belongs to `LabelingProject::compute_agreement`,
+// fed with actual annotations once a run has produced them.
+
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::SmartLabelingPlugin;
+
+/// A single workload to execute, loaded from a JSON file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Workload {
+    pub name: String,
+    pub task: String,
+    pub sample_source: String,
+    pub batch_size: usize,
+    pub repetitions: usize,
+}
+
+/// Throughput and latency numbers for one executed workload.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkloadResult {
+    pub workload_name: String,
+    pub task: String,
+    pub samples_processed: u64,
+    pub throughput_samples_per_sec: f64,
+    pub latency_p50_ms: f64,
+    pub latency_p95_ms: f64,
+}
+
+/// The outcome of executing a single workload file: either its results, or why
+/// it couldn't be run. Keeping both in the report means one bad workload file
+/// doesn't blank out results already produced by the others.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkloadOutcome {
+    pub workload_path: String,
+    pub result: Option<WorkloadResult>,
+    pub error: Option<String>,
+}
+
+/// The structured document emitted at the end of a benchmark run.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkReport {
+    pub run_id: String,
+    pub results: Vec<WorkloadOutcome>,
+}
+
+impl SmartLabelingPlugin {
+    /// Execute every workload file in `workload_paths` and return the combined
+    /// report. A workload file that's missing or malformed is recorded as a
+    /// failed outcome rather than aborting the rest of the run. When
+    /// `report_url` is set, the report is also POSTed there so runs can be
+    /// tracked over time.
+    pub async fn run_benchmarks(
+        &self,
+        workload_paths: &[String],
+        report_url: Option<&str>,
+    ) -> Result<BenchmarkReport> {
+        let run_id = env!("CARGO_PKG_VERSION").to_string();
+        let mut results = Vec::with_capacity(workload_paths.len());
+
+        for path in workload_paths {
+            let outcome = match load_workload(path).and_then(|workload| run_workload(&workload)) {
+                Ok(result) => WorkloadOutcome { workload_path: path.clone(), result: Some(result), error: None },
+                Err(err) => WorkloadOutcome { workload_path: path.clone(), result: None, error: Some(err.to_string()) },
+            };
+            results.push(outcome);
+        }
+
+        let report = BenchmarkReport { run_id, results };
+
+        if let Some(url) = report_url {
+            report_results(url, &report).await?;
+        }
+
+        Ok(report)
+    }
+}
+
+fn load_workload(path: &str) -> Result<Workload> {
+    let contents = fs::read_to_string(path)?;
+    let workload = serde_json::from_str(&contents)?;
+    Ok(workload)
+}
+
+fn run_workload(workload: &Workload) -> Result<WorkloadResult> {
+    let samples = load_samples(&workload.sample_source)?;
+    if samples.is_empty() {
+        anyhow::bail!("sample source {} contains no samples", workload.sample_source);
+    }
+
+    let mut latencies_ms = Vec::new();
+    let mut samples_processed: u64 = 0;
+
+    let overall_start = Instant::now();
+    for _ in 0..workload.repetitions {
+        for batch in samples.chunks(workload.batch_size.max(1)) {
+            for sample in batch {
+                let start = Instant::now();
+                label_sample(sample);
+                latencies_ms.push(start.elapsed().as_secs_f64() * 1000.0);
+                samples_processed += 1;
+            }
+        }
+    }
+    let elapsed_secs = overall_start.elapsed().as_secs_f64().max(f64::EPSILON);
+
+    latencies_ms.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    Ok(WorkloadResult {
+        workload_name: workload.name.clone(),
+        task: workload.task.clone(),
+        samples_processed,
+        throughput_samples_per_sec: samples_processed as f64 / elapsed_secs,
+        latency_p50_ms: percentile(&latencies_ms, 0.50),
+        latency_p95_ms: percentile(&latencies_ms, 0.95),
+    })
+}
+
+fn load_samples(sample_source: &str) -> Result<Vec<String>> {
+    let contents = fs::read_to_string(Path::new(sample_source))?;
+    Ok(contents.lines().filter(|line| !line.trim().is_empty()).map(String::from).collect())
+}
+
+/// Placeholder labeling step: the benchmark runner measures pipeline overhead,
+/// not model quality, so this does no real inference.
+fn label_sample(_sample: &str) {}
+
+fn percentile(sorted_values: &[f64], p: f64) -> f64 {
+    if sorted_values.is_empty() {
+        return 0.0;
+    }
+    let rank = (p * (sorted_values.len() - 1) as f64).round() as usize;
+    sorted_values[rank.min(sorted_values.len() - 1)]
+}
+
+async fn report_results(url: &str, report: &BenchmarkReport) -> Result<()> {
+    let client = reqwest::Client::new();
+    client
+        .post(url)
+        .json(report)
+        .send()
+        .await
+        .with_context(|| format!("failed to POST benchmark results to {}", url))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn a_bad_workload_file_does_not_blank_results_from_good_ones() {
+        let dir = std::env::temp_dir().join(format!("adios-benchmark-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let sample_path = dir.join("samples.txt");
+        fs::write(&sample_path, "sample one\nsample two\n").unwrap();
+
+        let good_workload_path = dir.join("good.json");
+        fs::write(
+            &good_workload_path,
+            serde_json::json!({
+                "name": "good",
+                "task": "text_classification",
+                "sample_source": sample_path.to_str().unwrap(),
+                "batch_size": 2,
+                "repetitions": 1,
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let missing_workload_path = dir.join("missing.json");
+
+        let plugin = SmartLabelingPlugin::new().await.unwrap();
+        let report = plugin
+            .run_benchmarks(
+                &[
+                    good_workload_path.to_str().unwrap().to_string(),
+                    missing_workload_path.to_str().unwrap().to_string(),
+                ],
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(report.results.len(), 2);
+        assert!(report.results[0].result.is_some());
+        assert!(report.results[0].error.is_none());
+        assert!(report.results[1].result.is_none());
+        assert!(report.results[1].error.is_some());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,201 @@
+// Image preprocessing for visual labeling tasks (`image_classification`,
+// `object_detection`): on ingest, derive cached thumbnail/preview variants and a
+// blurhash placeholder so large image datasets are navigable in the annotation UI
+// without repeatedly decoding full-resolution files.
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+const THUMBNAIL_MAX_DIM: u32 = 128;
+const PREVIEW_MAX_DIM: u32 = 768;
+const BLURHASH_X_COMPONENTS: u32 = 4;
+const BLURHASH_Y_COMPONENTS: u32 = 3;
+
+/// The kind of derived image a variant holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariantKind {
+    /// Small downscaled image for grid/list views.
+    Thumbnail,
+    /// Larger downscaled image sized for the annotation canvas.
+    Preview,
+}
+
+/// One cached derived image, keyed by the content hash of the sample it was
+/// generated from.
+#[derive(Debug, Clone)]
+pub struct ImageVariant {
+    pub id: Uuid,
+    pub sample_hash: String,
+    pub kind: VariantKind,
+    pub width: u32,
+    pub height: u32,
+    pub encoded: Vec<u8>,
+}
+
+/// Storage-agnostic access to a sample's derived image variants, so the backing
+/// store (in-memory, disk, object storage, ...) can be swapped.
+pub trait VariantRepo: Send + Sync {
+    /// Store an `encoded` variant of `kind` for `sample_hash` and return its generated id.
+    fn put_variant(&mut self, sample_hash: &str, kind: VariantKind, encoded: Vec<u8>, width: u32, height: u32) -> Uuid;
+
+    /// List every variant cached for `sample_hash`.
+    fn variants_for_sample(&self, sample_hash: &str) -> Vec<ImageVariant>;
+
+    /// Remove a stale variant by id.
+    fn remove_variant(&mut self, variant_id: Uuid);
+}
+
+/// Simple in-process `VariantRepo`, consistent with the rest of this plugin's
+/// in-memory state.
+#[derive(Debug, Default)]
+pub struct InMemoryVariantRepo {
+    variants: Vec<ImageVariant>,
+}
+
+impl VariantRepo for InMemoryVariantRepo {
+    fn put_variant(&mut self, sample_hash: &str, kind: VariantKind, encoded: Vec<u8>, width: u32, height: u32) -> Uuid {
+        let id = Uuid::new_v4();
+        self.variants.push(ImageVariant {
+            id,
+            sample_hash: sample_hash.to_string(),
+            kind,
+            width,
+            height,
+            encoded,
+        });
+        id
+    }
+
+    fn variants_for_sample(&self, sample_hash: &str) -> Vec<ImageVariant> {
+        self.variants
+            .iter()
+            .filter(|v| v.sample_hash == sample_hash)
+            .cloned()
+            .collect()
+    }
+
+    fn remove_variant(&mut self, variant_id: Uuid) {
+        self.variants.retain(|v| v.id != variant_id);
+    }
+}
+
+/// The result of ingesting one raw image: its content hash, a blurhash preview
+/// placeholder, and the derived variants that were cached.
+#[derive(Debug, Clone)]
+pub struct IngestedImage {
+    pub sample_hash: String,
+    pub blurhash: String,
+    pub variant_ids: Vec<Uuid>,
+}
+
+/// Content-hash `image_bytes`, generate thumbnail and preview variants, cache
+/// them in `repo`, and compute a blurhash placeholder for the original image.
+pub fn ingest_image(repo: &mut dyn VariantRepo, image_bytes: &[u8]) -> Result<IngestedImage> {
+    let sample_hash = content_hash(image_bytes);
+    let image = image::load_from_memory(image_bytes).context("failed to decode image")?;
+
+    let thumbnail = image.thumbnail(THUMBNAIL_MAX_DIM, THUMBNAIL_MAX_DIM);
+    let preview = image.thumbnail(PREVIEW_MAX_DIM, PREVIEW_MAX_DIM);
+
+    let mut variant_ids = Vec::with_capacity(2);
+    for (kind, variant) in [(VariantKind::Thumbnail, &thumbnail), (VariantKind::Preview, &preview)] {
+        let encoded = encode_png(variant)?;
+        variant_ids.push(repo.put_variant(&sample_hash, kind, encoded, variant.width(), variant.height()));
+    }
+
+    let blurhash = compute_blurhash(&image)?;
+
+    Ok(IngestedImage { sample_hash, blurhash, variant_ids })
+}
+
+fn content_hash(image_bytes: &[u8]) -> String {
+    let digest = Sha256::digest(image_bytes);
+    format!("{:x}", digest)
+}
+
+fn encode_png(image: &image::DynamicImage) -> Result<Vec<u8>> {
+    let mut encoded = Vec::new();
+    image.write_to(&mut std::io::Cursor::new(&mut encoded), image::ImageFormat::Png)?;
+    Ok(encoded)
+}
+
+fn compute_blurhash(image: &image::DynamicImage) -> Result<String> {
+    let rgba = image.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    let hash = blurhash::encode(BLURHASH_X_COMPONENTS, BLURHASH_Y_COMPONENTS, width, height, rgba.as_raw())
+        .context("failed to compute blurhash")?;
+    Ok(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn put_and_list_variants_round_trip() {
+        let mut repo = InMemoryVariantRepo::default();
+        let hash = "deadbeef";
+
+        let thumb_id = repo.put_variant(hash, VariantKind::Thumbnail, vec![1, 2, 3], 128, 96);
+        let preview_id = repo.put_variant(hash, VariantKind::Preview, vec![4, 5, 6], 768, 576);
+
+        let variants = repo.variants_for_sample(hash);
+        assert_eq!(variants.len(), 2);
+        assert!(variants.iter().any(|v| v.id == thumb_id && v.kind == VariantKind::Thumbnail));
+        assert!(variants.iter().any(|v| v.id == preview_id && v.kind == VariantKind::Preview));
+    }
+
+    #[test]
+    fn remove_variant_drops_only_that_variant() {
+        let mut repo = InMemoryVariantRepo::default();
+        let hash = "deadbeef";
+        let thumb_id = repo.put_variant(hash, VariantKind::Thumbnail, vec![1], 128, 128);
+        let preview_id = repo.put_variant(hash, VariantKind::Preview, vec![2], 768, 768);
+
+        repo.remove_variant(thumb_id);
+
+        let variants = repo.variants_for_sample(hash);
+        assert_eq!(variants.len(), 1);
+        assert_eq!(variants[0].id, preview_id);
+    }
+
+    #[test]
+    fn variants_for_unknown_sample_are_empty() {
+        let repo = InMemoryVariantRepo::default();
+        assert!(repo.variants_for_sample("unknown").is_empty());
+    }
+
+    #[test]
+    fn ingest_image_decodes_hashes_and_caches_variants_with_a_blurhash() {
+        let png_bytes = encode_test_png(32, 32);
+
+        let mut repo = InMemoryVariantRepo::default();
+        let ingested = ingest_image(&mut repo, &png_bytes).unwrap();
+
+        assert_eq!(ingested.sample_hash, content_hash(&png_bytes));
+        assert!(!ingested.blurhash.is_empty());
+        assert_eq!(ingested.variant_ids.len(), 2);
+
+        let variants = repo.variants_for_sample(&ingested.sample_hash);
+        assert_eq!(variants.len(), 2);
+
+        let thumbnail = variants.iter().find(|v| v.kind == VariantKind::Thumbnail).unwrap();
+        assert!(thumbnail.width <= THUMBNAIL_MAX_DIM && thumbnail.height <= THUMBNAIL_MAX_DIM);
+
+        let preview = variants.iter().find(|v| v.kind == VariantKind::Preview).unwrap();
+        assert!(preview.width <= PREVIEW_MAX_DIM && preview.height <= PREVIEW_MAX_DIM);
+    }
+
+    fn encode_test_png(width: u32, height: u32) -> Vec<u8> {
+        let buffer = image::ImageBuffer::from_fn(width, height, |x, y| {
+            if (x + y) % 2 == 0 {
+                image::Rgb([255u8, 0, 0])
+            } else {
+                image::Rgb([0u8, 0, 255])
+            }
+        });
+        let dynamic = image::DynamicImage::ImageRgb8(buffer);
+        encode_png(&dynamic).unwrap()
+    }
+}
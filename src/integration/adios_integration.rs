@@ -8,6 +8,7 @@ use adios_core::events::EventBus;
 use async_trait::async_trait;
 use std::sync::Arc;
 use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
 
 /// Plugin state for ecosystem integration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +32,10 @@ pub struct AdiosPlugin {
     state: PluginState,
     ctx: Option<Arc<AppContext>>,
     bus: Option<Arc<EventBus>>,
+    /// Shared handle to the smart-labeling plugin's state, attached via
+    /// `attach_labeling_state` so the ecosystem-driven `tick()` below can advance
+    /// its background job queue.
+    labeling_state: Option<Arc<RwLock<crate::PluginState>>>,
 }
 
 impl AdiosPlugin {
@@ -45,9 +50,17 @@ impl AdiosPlugin {
             },
             ctx: None,
             bus: None,
+            labeling_state: None,
         }
     }
-    
+
+    /// Attach the smart-labeling plugin's shared state so this plugin's `tick()`
+    /// can drive its job queue forward.
+    pub fn attach_labeling_state(&mut self, state: Arc<RwLock<crate::PluginState>>) {
+        self.labeling_state = Some(state);
+    }
+
+
     /// Get current plugin state
     pub fn state(&self) -> &PluginState {
         &self.state
@@ -137,7 +150,13 @@ impl Plugin for AdiosPlugin {
                 }
             }
         }
-        
+
+        // Drive the smart-labeling plugin's background job queue forward, if attached.
+        if let Some(labeling_state) = &self.labeling_state {
+            let mut labeling_state = labeling_state.write().await;
+            labeling_state.jobs.advance();
+        }
+
         Ok(())
     }
     
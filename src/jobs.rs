@@ -0,0 +1,203 @@
+// Background job queue and project maintenance.
+//
+// Long-running operations (bulk import, re-labeling a whole project, recomputing
+// QA metrics, reindexing) are tracked as jobs and driven forward incrementally from
+// `integration::AdiosPlugin::tick()`, the method the ecosystem's periodic loop
+// actually calls, instead of blocking the plugin loop or simply not existing.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{ProjectStatus, SmartLabelingPlugin};
+
+/// The kind of long-running operation a job performs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JobKind {
+    BulkImport,
+    RelabelProject,
+    RecomputeQaMetrics,
+    ReindexSampleStore,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// One unit of background work tracked by the job queue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Job {
+    pub id: Uuid,
+    pub kind: JobKind,
+    pub target_project: Uuid,
+    /// Completion percentage, 0-100.
+    pub progress: u8,
+    pub status: JobStatus,
+    pub enqueued_at: DateTime<Utc>,
+}
+
+/// The increment applied to a running job's progress on each `tick()`.
+const PROGRESS_STEP: u8 = 25;
+
+/// A queue of asynchronous maintenance jobs, driven forward one step at a time.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobQueue {
+    jobs: HashMap<Uuid, Job>,
+}
+
+impl JobQueue {
+    pub fn enqueue(&mut self, kind: JobKind, target_project: Uuid) -> Uuid {
+        let id = Uuid::new_v4();
+        self.jobs.insert(
+            id,
+            Job {
+                id,
+                kind,
+                target_project,
+                progress: 0,
+                status: JobStatus::Queued,
+                enqueued_at: Utc::now(),
+            },
+        );
+        id
+    }
+
+    /// Advance every queued or running job by one step.
+    pub fn advance(&mut self) {
+        for job in self.jobs.values_mut() {
+            match job.status {
+                JobStatus::Queued => job.status = JobStatus::Running,
+                JobStatus::Running => {
+                    job.progress = (job.progress + PROGRESS_STEP).min(100);
+                    if job.progress >= 100 {
+                        job.status = JobStatus::Completed;
+                    }
+                }
+                JobStatus::Completed | JobStatus::Failed => {}
+            }
+        }
+    }
+
+    /// All jobs currently tracked, active or finished, most recently enqueued first.
+    pub fn all(&self) -> Vec<Job> {
+        let mut jobs: Vec<Job> = self.jobs.values().cloned().collect();
+        jobs.sort_by(|a, b| b.enqueued_at.cmp(&a.enqueued_at));
+        jobs
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<&Job> {
+        self.jobs.get(&id)
+    }
+}
+
+impl SmartLabelingPlugin {
+    /// Queue reindexing of a project's sample store and return the job id.
+    pub async fn reindex_project(&self, project_id: Uuid) -> Uuid {
+        let mut state = self.state.write().await;
+        state.jobs.enqueue(JobKind::ReindexSampleStore, project_id)
+    }
+
+    /// List active and recently finished jobs.
+    pub async fn list_jobs(&self) -> Vec<Job> {
+        self.state.read().await.jobs.all()
+    }
+
+    /// Remove completed and failed projects from `active_projects`, returning
+    /// the ids that were pruned.
+    pub async fn prune_completed_projects(&self) -> Vec<Uuid> {
+        let mut state = self.state.write().await;
+        let to_remove: Vec<Uuid> = state
+            .active_projects
+            .iter()
+            .filter(|(_, project)| {
+                matches!(project.status, ProjectStatus::Completed | ProjectStatus::Failed)
+            })
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in &to_remove {
+            state.active_projects.remove(id);
+        }
+
+        to_remove
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_moves_queued_job_through_to_completed() {
+        let mut queue = JobQueue::default();
+        let project_id = Uuid::new_v4();
+        let id = queue.enqueue(JobKind::ReindexSampleStore, project_id);
+
+        assert_eq!(queue.get(id).unwrap().status, JobStatus::Queued);
+
+        queue.advance();
+        assert_eq!(queue.get(id).unwrap().status, JobStatus::Running);
+        assert_eq!(queue.get(id).unwrap().progress, 0);
+
+        for _ in 0..4 {
+            queue.advance();
+        }
+
+        assert_eq!(queue.get(id).unwrap().status, JobStatus::Completed);
+        assert_eq!(queue.get(id).unwrap().progress, 100);
+    }
+
+    #[test]
+    fn all_orders_jobs_most_recently_enqueued_first() {
+        let mut queue = JobQueue::default();
+        let first = queue.enqueue(JobKind::BulkImport, Uuid::new_v4());
+        let second = queue.enqueue(JobKind::ReindexSampleStore, Uuid::new_v4());
+
+        // Uuid::new_v4() ids are random and carry no temporal ordering, so bump
+        // the first job's enqueued_at backwards to simulate it having been queued
+        // earlier, independent of id ordering.
+        queue.jobs.get_mut(&first).unwrap().enqueued_at -= chrono::Duration::minutes(5);
+
+        let ordered: Vec<Uuid> = queue.all().into_iter().map(|j| j.id).collect();
+        assert_eq!(ordered, vec![second, first]);
+    }
+
+    #[tokio::test]
+    async fn prune_completed_projects_removes_finished_projects_only() {
+        let plugin = SmartLabelingPlugin::new().await.unwrap();
+        let finished_id = Uuid::new_v4();
+        let active_id = Uuid::new_v4();
+
+        {
+            let mut state = plugin.state.write().await;
+            state.active_projects.insert(finished_id, test_project(finished_id, ProjectStatus::Completed));
+            state.active_projects.insert(active_id, test_project(active_id, ProjectStatus::InProgress));
+        }
+
+        let pruned = plugin.prune_completed_projects().await;
+        assert_eq!(pruned, vec![finished_id]);
+
+        let state = plugin.state.read().await;
+        assert!(!state.active_projects.contains_key(&finished_id));
+        assert!(state.active_projects.contains_key(&active_id));
+    }
+
+    fn test_project(id: Uuid, status: ProjectStatus) -> crate::LabelingProject {
+        crate::LabelingProject {
+            id,
+            name: "test".to_string(),
+            task_type: "text_classification".to_string(),
+            status,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            progress: 0.0,
+            schema: None,
+        }
+    }
+}
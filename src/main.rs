@@ -1,4 +1,10 @@
+mod active_learning;
+mod benchmark;
+mod image_variants;
 mod integration;
+mod jobs;
+mod quality_assurance;
+mod schema;
 // AdiOS Smart Labeling Plugin
 // 
 // AI-powered smart labeling and annotation platform with context-aware labeling using Organization Brain.
@@ -6,18 +12,22 @@ mod integration;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use tracing::info;
 
+use schema::AnnotationSchema;
+
 /// Main plugin structure for AdiOS Smart Labeling service
 pub struct SmartLabelingPlugin {
     /// Plugin metadata and configuration
     info: PluginInfo,
-    
-    /// Current state of the plugin
-    state: RwLock<PluginState>,
+
+    /// Current state of the plugin, shared with `integration::AdiosPlugin` so its
+    /// ecosystem-driven `tick()` can advance the job queue.
+    pub(crate) state: Arc<RwLock<PluginState>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,6 +50,9 @@ pub struct PluginState {
     
     /// Plugin configuration
     pub config: PluginConfig,
+
+    /// Background maintenance job queue
+    pub jobs: jobs::JobQueue,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +64,9 @@ pub struct LabelingProject {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub progress: f32,
+
+    /// Custom annotation schema attached to this project, if any (Professional tier).
+    pub schema: Option<AnnotationSchema>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,6 +109,7 @@ impl Default for PluginState {
                 max_concurrent_projects: 10,
                 quality_assurance_enabled: true,
             },
+            jobs: jobs::JobQueue::default(),
         }
     }
 }
@@ -108,14 +125,20 @@ impl SmartLabelingPlugin {
             category: "enterprise".to_string(),
         };
         
-        let state = RwLock::new(PluginState::default());
-        
+        let state = Arc::new(RwLock::new(PluginState::default()));
+
         Ok(Self {
             info,
             state,
         })
     }
-    
+
+    /// A shared handle to this plugin's state, for `integration::AdiosPlugin` to
+    /// attach so ecosystem lifecycle calls (like `tick()`) can drive it forward.
+    pub fn state_handle(&self) -> Arc<RwLock<PluginState>> {
+        Arc::clone(&self.state)
+    }
+
     pub fn name(&self) -> &str {
         &self.info.name
     }
@@ -279,11 +302,32 @@ async fn main() -> Result<()> {
     // Initialize logging
     tracing_subscriber::fmt()
         .init();
-    
-    // Create and run plugin
+
+    // Create the plugin
     let plugin = SmartLabelingPlugin::new().await?;
-    plugin.run().await?;
-    
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("benchmark") => {
+            let mut workload_paths = Vec::new();
+            let mut report_url = None;
+            let mut rest = args[1..].iter();
+            while let Some(arg) = rest.next() {
+                if arg == "--report-url" {
+                    report_url = rest.next().cloned();
+                } else {
+                    workload_paths.push(arg.clone());
+                }
+            }
+
+            let report = plugin.run_benchmarks(&workload_paths, report_url.as_deref()).await?;
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        _ => {
+            plugin.run().await?;
+        }
+    }
+
     Ok(())
 }
 
@@ -0,0 +1,270 @@
+// Quality assurance subsystem: inter-annotator agreement for samples labeled by
+// more than one annotator.
+//
+// Backs `SystemMetrics.accuracy_score`, which was previously an unused placeholder.
+// `compute_agreement` does not read `PluginConfig.quality_assurance_enabled`.
+
+use std::collections::{HashMap, HashSet};
+
+use uuid::Uuid;
+
+use crate::{LabelingProject, SystemMetrics};
+
+/// One annotator's label for one sample.
+#[derive(Debug, Clone)]
+pub struct AnnotationRecord {
+    pub sample_id: Uuid,
+    pub annotator_id: Uuid,
+    pub category: String,
+}
+
+/// Result of an agreement pass: the overall kappa statistic plus the samples whose
+/// per-item agreement fell below the review threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgreementReport {
+    pub kappa: f64,
+    pub review_queue: Vec<Uuid>,
+}
+
+impl LabelingProject {
+    /// Compute inter-annotator agreement over `annotations`, store the resulting
+    /// kappa into `metrics.accuracy_score`, and return the items falling below
+    /// `review_threshold` for adjudication.
+    ///
+    /// Uses Cohen's kappa when exactly two annotators contributed labels, and
+    /// Fleiss' kappa otherwise.
+    pub fn compute_agreement(
+        &self,
+        annotations: &[AnnotationRecord],
+        review_threshold: f64,
+        metrics: &mut SystemMetrics,
+    ) -> AgreementReport {
+        let annotators: HashSet<Uuid> = annotations.iter().map(|a| a.annotator_id).collect();
+
+        let mut by_item: HashMap<Uuid, Vec<&AnnotationRecord>> = HashMap::new();
+        for annotation in annotations {
+            by_item.entry(annotation.sample_id).or_default().push(annotation);
+        }
+
+        let (kappa, review_queue) = if annotators.len() == 2 {
+            let mut ordered: Vec<Uuid> = annotators.into_iter().collect();
+            ordered.sort();
+            let (a1, a2) = (ordered[0], ordered[1]);
+            let kappa = cohens_kappa(&by_item, a1, a2);
+            // With two raters an item's agreement is 1.0 when they agree, 0.0 otherwise.
+            let review_queue = by_item
+                .iter()
+                .filter(|(_, items)| {
+                    let p_i = if items_agree(items) { 1.0 } else { 0.0 };
+                    p_i < review_threshold
+                })
+                .map(|(sample_id, _)| *sample_id)
+                .collect();
+            (kappa, review_queue)
+        } else {
+            fleiss_kappa(&by_item, review_threshold)
+        };
+
+        metrics.accuracy_score = kappa;
+
+        AgreementReport { kappa, review_queue }
+    }
+}
+
+fn items_agree(items: &[&AnnotationRecord]) -> bool {
+    items.windows(2).all(|w| w[0].category == w[1].category)
+}
+
+fn cohens_kappa(by_item: &HashMap<Uuid, Vec<&AnnotationRecord>>, a1: Uuid, a2: Uuid) -> f64 {
+    let mut pairs: Vec<(&str, &str)> = Vec::new();
+    for items in by_item.values() {
+        let label1 = items.iter().find(|a| a.annotator_id == a1).map(|a| a.category.as_str());
+        let label2 = items.iter().find(|a| a.annotator_id == a2).map(|a| a.category.as_str());
+        if let (Some(l1), Some(l2)) = (label1, label2) {
+            pairs.push((l1, l2));
+        }
+    }
+
+    if pairs.is_empty() {
+        return 0.0;
+    }
+
+    let n = pairs.len() as f64;
+    let agreed = pairs.iter().filter(|(l1, l2)| l1 == l2).count() as f64;
+    let p_o = agreed / n;
+
+    let mut r1_counts: HashMap<&str, f64> = HashMap::new();
+    let mut r2_counts: HashMap<&str, f64> = HashMap::new();
+    for (l1, l2) in &pairs {
+        *r1_counts.entry(l1).or_insert(0.0) += 1.0;
+        *r2_counts.entry(l2).or_insert(0.0) += 1.0;
+    }
+
+    let categories: HashSet<&str> = r1_counts.keys().chain(r2_counts.keys()).copied().collect();
+    let p_e: f64 = categories
+        .iter()
+        .map(|category| {
+            let r1 = r1_counts.get(category).copied().unwrap_or(0.0) / n;
+            let r2 = r2_counts.get(category).copied().unwrap_or(0.0) / n;
+            r1 * r2
+        })
+        .sum();
+
+    if (1.0 - p_e).abs() < f64::EPSILON {
+        1.0
+    } else {
+        (p_o - p_e) / (1.0 - p_e)
+    }
+}
+
+fn fleiss_kappa(
+    by_item: &HashMap<Uuid, Vec<&AnnotationRecord>>,
+    review_threshold: f64,
+) -> (f64, Vec<Uuid>) {
+    let n_items = by_item.len() as f64;
+    if n_items == 0.0 {
+        return (0.0, Vec::new());
+    }
+
+    let categories: HashSet<&str> = by_item
+        .values()
+        .flat_map(|items| items.iter().map(|a| a.category.as_str()))
+        .collect();
+
+    let mut p_i_by_item: HashMap<Uuid, f64> = HashMap::new();
+    let mut category_totals: HashMap<&str, f64> = HashMap::new();
+    let mut total_raters = 0.0;
+
+    for (sample_id, items) in by_item {
+        let n_raters = items.len() as f64;
+        total_raters += n_raters;
+
+        let mut n_ij: HashMap<&str, f64> = HashMap::new();
+        for item in items {
+            *n_ij.entry(item.category.as_str()).or_insert(0.0) += 1.0;
+            *category_totals.entry(item.category.as_str()).or_insert(0.0) += 1.0;
+        }
+
+        let p_i = if n_raters > 1.0 {
+            let sum_sq: f64 = categories.iter().map(|c| {
+                let n = n_ij.get(c).copied().unwrap_or(0.0);
+                n * n
+            }).sum();
+            (sum_sq - n_raters) / (n_raters * (n_raters - 1.0))
+        } else {
+            1.0
+        };
+
+        p_i_by_item.insert(*sample_id, p_i);
+    }
+
+    let p_bar = p_i_by_item.values().sum::<f64>() / n_items;
+    let p_e: f64 = category_totals
+        .values()
+        .map(|total| {
+            let p = total / total_raters;
+            p * p
+        })
+        .sum();
+
+    let kappa = if (1.0 - p_e).abs() < f64::EPSILON {
+        1.0
+    } else {
+        (p_bar - p_e) / (1.0 - p_e)
+    };
+
+    let review_queue = p_i_by_item
+        .into_iter()
+        .filter(|(_, p_i)| *p_i < review_threshold)
+        .map(|(sample_id, _)| sample_id)
+        .collect();
+
+    (kappa, review_queue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ProjectStatus, SystemMetrics};
+    use chrono::Utc;
+
+    fn test_project() -> LabelingProject {
+        LabelingProject {
+            id: Uuid::new_v4(),
+            name: "test".to_string(),
+            task_type: "text_classification".to_string(),
+            status: ProjectStatus::InProgress,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            progress: 0.0,
+            schema: None,
+        }
+    }
+
+    fn test_metrics() -> SystemMetrics {
+        SystemMetrics {
+            total_projects: 0,
+            active_project_count: 0,
+            samples_labeled: 0,
+            accuracy_score: 0.0,
+        }
+    }
+
+    #[test]
+    fn cohens_kappa_is_perfect_for_full_agreement() {
+        let project = test_project();
+        let mut metrics = test_metrics();
+        let (a1, a2) = (Uuid::new_v4(), Uuid::new_v4());
+        let s1 = Uuid::new_v4();
+        let s2 = Uuid::new_v4();
+
+        let annotations = vec![
+            AnnotationRecord { sample_id: s1, annotator_id: a1, category: "cat".to_string() },
+            AnnotationRecord { sample_id: s1, annotator_id: a2, category: "cat".to_string() },
+            AnnotationRecord { sample_id: s2, annotator_id: a1, category: "dog".to_string() },
+            AnnotationRecord { sample_id: s2, annotator_id: a2, category: "dog".to_string() },
+        ];
+
+        let report = project.compute_agreement(&annotations, 0.5, &mut metrics);
+        assert!((report.kappa - 1.0).abs() < 1e-9);
+        assert!(report.review_queue.is_empty());
+        assert!((metrics.accuracy_score - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cohens_kappa_flags_disagreeing_item() {
+        let project = test_project();
+        let mut metrics = test_metrics();
+        let (a1, a2) = (Uuid::new_v4(), Uuid::new_v4());
+        let s1 = Uuid::new_v4();
+
+        let annotations = vec![
+            AnnotationRecord { sample_id: s1, annotator_id: a1, category: "cat".to_string() },
+            AnnotationRecord { sample_id: s1, annotator_id: a2, category: "dog".to_string() },
+        ];
+
+        let report = project.compute_agreement(&annotations, 0.5, &mut metrics);
+        assert_eq!(report.review_queue, vec![s1]);
+    }
+
+    #[test]
+    fn fleiss_kappa_flags_low_agreement_items() {
+        let project = test_project();
+        let mut metrics = test_metrics();
+        let (a1, a2, a3) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let agree_item = Uuid::new_v4();
+        let split_item = Uuid::new_v4();
+
+        let annotations = vec![
+            AnnotationRecord { sample_id: agree_item, annotator_id: a1, category: "cat".to_string() },
+            AnnotationRecord { sample_id: agree_item, annotator_id: a2, category: "cat".to_string() },
+            AnnotationRecord { sample_id: agree_item, annotator_id: a3, category: "cat".to_string() },
+            AnnotationRecord { sample_id: split_item, annotator_id: a1, category: "cat".to_string() },
+            AnnotationRecord { sample_id: split_item, annotator_id: a2, category: "dog".to_string() },
+            AnnotationRecord { sample_id: split_item, annotator_id: a3, category: "bird".to_string() },
+        ];
+
+        let report = project.compute_agreement(&annotations, 0.5, &mut metrics);
+        assert_eq!(report.review_queue, vec![split_item]);
+    }
+}
@@ -0,0 +1,293 @@
+// User-defined annotation schemas.
+//
+// Backs the Professional tier's "Custom annotation schemas" feature: projects can
+// define annotation types with typed custom attributes beyond the built-in label
+// field, and every submitted annotation is validated against the project's schema.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::LabelingProject;
+
+/// The allowed shape of a custom attribute's value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AttributeType {
+    String,
+    Enum(Vec<String>),
+    Numeric { min: f64, max: f64 },
+    Date,
+    Bool,
+}
+
+/// One custom attribute defined on an annotation schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttributeDefinition {
+    pub name: String,
+    pub attribute_type: AttributeType,
+    pub required: bool,
+}
+
+/// A versioned definition of the custom attributes a project's annotations carry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnotationSchema {
+    pub version: u32,
+    pub attributes: Vec<AttributeDefinition>,
+}
+
+impl AnnotationSchema {
+    pub fn new(attributes: Vec<AttributeDefinition>) -> Self {
+        Self { version: 1, attributes }
+    }
+
+    fn attribute(&self, name: &str) -> Option<&AttributeDefinition> {
+        self.attributes.iter().find(|a| a.name == name)
+    }
+
+    /// Validate `annotation` against this schema: every required attribute must be
+    /// present, and every present attribute's value must match its declared type.
+    pub fn validate(&self, annotation: &Annotation) -> Result<(), SchemaError> {
+        for definition in &self.attributes {
+            match annotation.attributes.get(&definition.name) {
+                Some(value) => validate_value(definition, value)?,
+                None if definition.required => {
+                    return Err(SchemaError::MissingAttribute(definition.name.clone()))
+                }
+                None => {}
+            }
+        }
+
+        for name in annotation.attributes.keys() {
+            if self.attribute(name).is_none() {
+                return Err(SchemaError::UnknownAttribute(name.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Bring an annotation written under an older schema version up to date so it
+    /// validates cleanly against this (current) schema: attributes that have since
+    /// been removed or renamed are dropped, missing attributes introduced since it
+    /// was written are left absent (validation surfaces it if one is now
+    /// required), and the annotation's recorded version is bumped to match.
+    pub fn migrate(&self, mut annotation: Annotation) -> Annotation {
+        annotation.attributes.retain(|name, _| self.attribute(name).is_some());
+        annotation.schema_version = self.version;
+        annotation
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SchemaError {
+    #[error("missing required attribute `{0}`")]
+    MissingAttribute(String),
+    #[error("annotation carries undefined attribute `{0}`")]
+    UnknownAttribute(String),
+    #[error("attribute `{0}` has the wrong type")]
+    WrongType(String),
+    #[error("attribute `{0}` value is out of range")]
+    OutOfRange(String),
+    #[error("attribute `{0}` is not one of the allowed enum values")]
+    InvalidEnumValue(String),
+}
+
+fn validate_value(definition: &AttributeDefinition, value: &AttributeValue) -> Result<(), SchemaError> {
+    match (&definition.attribute_type, value) {
+        (AttributeType::String, AttributeValue::String(_)) => Ok(()),
+        (AttributeType::Bool, AttributeValue::Bool(_)) => Ok(()),
+        (AttributeType::Date, AttributeValue::Date(_)) => Ok(()),
+        (AttributeType::Numeric { min, max }, AttributeValue::Numeric(n)) => {
+            // Every comparison against NaN is false, so `n < min || n > max` would
+            // silently pass a NaN value as in-range; reject non-finite values outright.
+            if !n.is_finite() || n < min || n > max {
+                Err(SchemaError::OutOfRange(definition.name.clone()))
+            } else {
+                Ok(())
+            }
+        }
+        (AttributeType::Enum(allowed), AttributeValue::Enum(value)) => {
+            if allowed.contains(value) {
+                Ok(())
+            } else {
+                Err(SchemaError::InvalidEnumValue(definition.name.clone()))
+            }
+        }
+        _ => Err(SchemaError::WrongType(definition.name.clone())),
+    }
+}
+
+/// A typed custom attribute value attached to an annotation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum AttributeValue {
+    String(String),
+    Enum(String),
+    Numeric(f64),
+    Date(DateTime<Utc>),
+    Bool(bool),
+}
+
+/// A single annotation submitted against a project's schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Annotation {
+    pub sample_id: Uuid,
+    pub label: String,
+    pub attributes: HashMap<String, AttributeValue>,
+    pub notes: Option<String>,
+    pub schema_version: u32,
+}
+
+impl LabelingProject {
+    /// Validate and, if necessary, migrate `annotation` against this project's
+    /// schema. Returns the migrated annotation on success.
+    pub fn validate_annotation(&self, annotation: Annotation) -> Result<Annotation, SchemaError> {
+        let schema = match &self.schema {
+            Some(schema) => schema,
+            None => return Ok(annotation),
+        };
+
+        let annotation = if annotation.schema_version < schema.version {
+            schema.migrate(annotation)
+        } else {
+            annotation
+        };
+
+        schema.validate(&annotation)?;
+        Ok(annotation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProjectStatus;
+    use chrono::Utc;
+
+    fn schema() -> AnnotationSchema {
+        AnnotationSchema::new(vec![
+            AttributeDefinition {
+                name: "severity".to_string(),
+                attribute_type: AttributeType::Enum(vec!["low".to_string(), "high".to_string()]),
+                required: true,
+            },
+            AttributeDefinition {
+                name: "confidence".to_string(),
+                attribute_type: AttributeType::Numeric { min: 0.0, max: 1.0 },
+                required: false,
+            },
+        ])
+    }
+
+    fn project_with_schema(schema: Option<AnnotationSchema>) -> LabelingProject {
+        LabelingProject {
+            id: Uuid::new_v4(),
+            name: "test".to_string(),
+            task_type: "text_classification".to_string(),
+            status: ProjectStatus::InProgress,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            progress: 0.0,
+            schema,
+        }
+    }
+
+    #[test]
+    fn validates_required_attributes() {
+        let project = project_with_schema(Some(schema()));
+        let annotation = Annotation {
+            sample_id: Uuid::new_v4(),
+            label: "bug".to_string(),
+            attributes: HashMap::new(),
+            notes: None,
+            schema_version: 1,
+        };
+
+        let result = project.validate_annotation(annotation);
+        assert_eq!(result.unwrap_err(), SchemaError::MissingAttribute("severity".to_string()));
+    }
+
+    #[test]
+    fn rejects_out_of_range_numeric_attribute() {
+        let project = project_with_schema(Some(schema()));
+        let mut attributes = HashMap::new();
+        attributes.insert("severity".to_string(), AttributeValue::Enum("low".to_string()));
+        attributes.insert("confidence".to_string(), AttributeValue::Numeric(1.5));
+        let annotation = Annotation {
+            sample_id: Uuid::new_v4(),
+            label: "bug".to_string(),
+            attributes,
+            notes: None,
+            schema_version: 1,
+        };
+
+        let result = project.validate_annotation(annotation);
+        assert_eq!(result.unwrap_err(), SchemaError::OutOfRange("confidence".to_string()));
+    }
+
+    #[test]
+    fn rejects_nan_numeric_attribute() {
+        let project = project_with_schema(Some(schema()));
+        let mut attributes = HashMap::new();
+        attributes.insert("severity".to_string(), AttributeValue::Enum("low".to_string()));
+        attributes.insert("confidence".to_string(), AttributeValue::Numeric(f64::NAN));
+        let annotation = Annotation {
+            sample_id: Uuid::new_v4(),
+            label: "bug".to_string(),
+            attributes,
+            notes: None,
+            schema_version: 1,
+        };
+
+        let result = project.validate_annotation(annotation);
+        assert_eq!(result.unwrap_err(), SchemaError::OutOfRange("confidence".to_string()));
+    }
+
+    #[test]
+    fn migrates_older_annotations_to_current_version() {
+        let mut current_schema = schema();
+        current_schema.version = 2;
+        let project = project_with_schema(Some(current_schema));
+
+        let mut attributes = HashMap::new();
+        attributes.insert("severity".to_string(), AttributeValue::Enum("high".to_string()));
+        let annotation = Annotation {
+            sample_id: Uuid::new_v4(),
+            label: "bug".to_string(),
+            attributes,
+            notes: None,
+            schema_version: 1,
+        };
+
+        let migrated = project.validate_annotation(annotation).unwrap();
+        assert_eq!(migrated.schema_version, 2);
+    }
+
+    #[test]
+    fn migrate_drops_attributes_retired_from_the_current_schema() {
+        // "confidence" existed when the annotation was written but has since been
+        // removed from the schema; migration should drop it rather than let it
+        // fail validation as an unknown attribute.
+        let mut current_schema = schema();
+        current_schema.version = 2;
+        current_schema.attributes.retain(|a| a.name != "confidence");
+        let project = project_with_schema(Some(current_schema));
+
+        let mut attributes = HashMap::new();
+        attributes.insert("severity".to_string(), AttributeValue::Enum("high".to_string()));
+        attributes.insert("confidence".to_string(), AttributeValue::Numeric(0.8));
+        let annotation = Annotation {
+            sample_id: Uuid::new_v4(),
+            label: "bug".to_string(),
+            attributes,
+            notes: None,
+            schema_version: 1,
+        };
+
+        let migrated = project.validate_annotation(annotation).unwrap();
+        assert_eq!(migrated.schema_version, 2);
+        assert!(!migrated.attributes.contains_key("confidence"));
+    }
+}